@@ -1,8 +1,9 @@
 //! This module contains the `Curve`/`CurveAffine` abstractions that allow us to
 //! write code that generalizes over a pair of groups.
 
+use ff::{Field, PrimeField};
 use group::prime::{PrimeCurve, PrimeCurveAffine};
-use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 use super::{FieldExt, Group};
 
@@ -10,6 +11,8 @@ use core::ops::{Add, Mul, Sub};
 
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 //#[cfg(feature = "std")]
 //use std::io::{self, Read, Write};
 
@@ -87,6 +90,28 @@ pub trait CurveExt:
     /// ```
     fn unboxed_hash_to_curve(domain_prefix: &str, message: &[u8]) -> Self;
 
+    /// Requests an encoder that accepts messages and returns elements in
+    /// the group, given domain prefix `domain_prefix`.
+    ///
+    /// This is the non-uniform `encode_to_curve` variant from RFC 9380:
+    /// unlike [`Self::hash_to_curve`], its output is *not* indifferentiable
+    /// from a random oracle (two distinct messages can map to related
+    /// points), so it must not be used where a random oracle is required
+    /// (e.g. Pedersen commitments). It is appropriate when a uniform
+    /// distribution is not needed, such as deriving a deterministic
+    /// nothing-up-my-sleeve generator, and costs roughly half of
+    /// `hash_to_curve` since it only performs a single map-to-curve.
+    ///
+    /// Deliberately has no default body: like [`Self::hash_to_curve`], the
+    /// map-to-curve step generally runs against a distinct isogenous curve
+    /// (see `hash_to_curve2::Hasher`'s separate `IsoCurve` type parameter),
+    /// so there is no implementation here that's correct for every curve.
+    #[cfg(feature = "alloc")]
+    fn encode_to_curve<'a>(domain_prefix: &'a str) -> Box<dyn Fn(&[u8]) -> Self + 'a>;
+
+    /// Unboxed version of encode_to_curve.
+    fn unboxed_encode_to_curve(domain_prefix: &str, message: &[u8]) -> Self;
+
     /// Returns whether or not this element is on the curve; should
     /// always be true unless an "unchecked" API was used.
     fn is_on_curve(&self) -> Choice;
@@ -100,6 +125,175 @@ pub trait CurveExt:
     /// Obtains a point given Jacobian coordinates $X : Y : Z$, failing
     /// if the coordinates are not on the curve.
     fn new_jacobian(x: Self::Base, y: Self::Base, z: Self::Base) -> CtOption<Self>;
+
+    /// Computes a multi-scalar multiplication $\sum_i \text{coeffs}_i \cdot \text{bases}_i$
+    /// using Pippenger's windowed bucket method.
+    ///
+    /// This is much faster than performing each scalar multiplication independently
+    /// and summing the results, which is especially useful for Pedersen and IPA-style
+    /// commitments over large numbers of bases.
+    ///
+    /// Panics if `coeffs` and `bases` do not have the same length.
+    #[cfg(feature = "alloc")]
+    fn msm(coeffs: &[Self::ScalarExt], bases: &[Self::AffineExt]) -> Self {
+        assert_eq!(coeffs.len(), bases.len());
+
+        // Bucket entries start empty, become an affine point on the first
+        // addition, and are promoted to projective on the second (and any
+        // subsequent) addition, so that a bucket's first accumulation is a
+        // mixed affine+projective addition and no field inversion is needed.
+        enum Bucket<C: CurveExt> {
+            None,
+            Affine(C::AffineExt),
+            Projective(C),
+        }
+
+        impl<C: CurveExt> Bucket<C> {
+            fn add_assign(&mut self, other: &C::AffineExt) {
+                *self = match self {
+                    Bucket::None => Bucket::Affine(*other),
+                    Bucket::Affine(a) => Bucket::Projective(C::from(*a) + *other),
+                    Bucket::Projective(p) => Bucket::Projective(*p + *other),
+                };
+            }
+
+            fn add_to(&self, acc: &mut C) {
+                match self {
+                    Bucket::None => (),
+                    Bucket::Affine(a) => *acc += *a,
+                    Bucket::Projective(p) => *acc += *p,
+                }
+            }
+        }
+
+        let window_size = if coeffs.len() < 32 {
+            3
+        } else {
+            ((coeffs.len() as f64).ln() * 69f64 / 100f64) as usize + 2
+        };
+
+        let scalars: Vec<_> = coeffs.iter().map(|s| s.to_repr()).collect();
+        let num_bits = 8 * scalars.first().map(|s| s.as_ref().len()).unwrap_or(0);
+        let num_buckets = (1 << window_size) - 1;
+
+        // Extracts the `window_size`-bit digit of `scalar` starting at bit `bit_offset`.
+        fn get_booth_index(bit_offset: usize, window_size: usize, bytes: &[u8]) -> usize {
+            let skip_bits = bit_offset - (bit_offset / 8) * 8;
+            let skip_bytes = bit_offset / 8;
+
+            let mut v = [0u8; 8];
+            for (dst, src) in v.iter_mut().zip(bytes[skip_bytes..].iter()) {
+                *dst = *src;
+            }
+
+            let mut tmp = u64::from_le_bytes(v);
+            tmp >>= skip_bits;
+            tmp &= (1 << window_size) - 1;
+
+            tmp as usize
+        }
+
+        let mut acc = Self::identity();
+        let mut bit_offset = num_bits;
+        while bit_offset > 0 {
+            // The final window is narrower than `window_size` whenever it
+            // doesn't evenly divide `num_bits`; using `window_size` here
+            // unconditionally would re-read bits already consumed by the
+            // previous window, so the window width actually consumed this
+            // iteration is capped to what's left.
+            let new_bit_offset = bit_offset.saturating_sub(window_size);
+            let window = bit_offset - new_bit_offset;
+            bit_offset = new_bit_offset;
+
+            for _ in 0..window {
+                acc = acc.double();
+            }
+
+            let mut buckets: Vec<Bucket<Self>> = (0..num_buckets).map(|_| Bucket::None).collect();
+            for (scalar, base) in scalars.iter().zip(bases.iter()) {
+                let digit = get_booth_index(bit_offset, window, scalar.as_ref());
+                if digit > 0 {
+                    buckets[digit - 1].add_assign(base);
+                }
+            }
+
+            let mut window_acc = Self::identity();
+            let mut running_sum = Self::identity();
+            for bucket in buckets.iter().rev() {
+                bucket.add_to(&mut running_sum);
+                window_acc += running_sum;
+            }
+
+            acc += window_acc;
+        }
+
+        acc
+    }
+
+    /// Converts a slice of projective points to affine using a single field
+    /// inversion for the whole batch, via Montgomery's trick, instead of
+    /// one inversion per point.
+    ///
+    /// This is a large win for MSM bucket reduction (see [`Self::msm`]) and
+    /// for batch-verifying or serializing many commitments, where
+    /// per-point inversions would otherwise dominate.
+    ///
+    /// Panics if `points` and `out` do not have the same length.
+    #[cfg(feature = "alloc")]
+    fn batch_normalize(points: &[Self], out: &mut [Self::AffineExt]) {
+        assert_eq!(points.len(), out.len());
+
+        if points.is_empty() {
+            return;
+        }
+
+        // Running product of the Zs seen so far, skipping the identity
+        // (Z = 0) since it has no multiplicative inverse and maps directly
+        // to the affine identity.
+        let mut running_product = Vec::with_capacity(points.len());
+        let mut acc = Self::Base::one();
+        for point in points {
+            let (_, _, z) = point.jacobian_coordinates();
+            acc = Self::Base::conditional_select(&(acc * z), &acc, z.ct_eq(&Self::Base::zero()));
+            running_product.push(acc);
+        }
+
+        // A single inversion of the final product stands in for one
+        // inversion per point; the individual Z⁻¹s are recovered below by
+        // walking the running products backwards.
+        let mut acc_inv = acc.invert().unwrap_or(Self::Base::zero());
+
+        for (point, (out, running_product)) in points
+            .iter()
+            .rev()
+            .zip(out.iter_mut().rev().zip(
+                running_product[..running_product.len() - 1]
+                    .iter()
+                    .rev()
+                    .chain(core::iter::once(&Self::Base::one())),
+            ))
+        {
+            let (x, y, z) = point.jacobian_coordinates();
+            let is_identity = z.ct_eq(&Self::Base::zero());
+
+            // z_inv is this point's Z⁻¹: the running product up to the
+            // previous point times the overall inverse.
+            let z_inv = Self::Base::conditional_select(
+                &(acc_inv * running_product),
+                &Self::Base::zero(),
+                is_identity,
+            );
+            acc_inv = Self::Base::conditional_select(&(acc_inv * z), &acc_inv, is_identity);
+
+            let z_inv2 = z_inv.square();
+            let z_inv3 = z_inv2 * z_inv;
+            let affine_x = x * z_inv2;
+            let affine_y = y * z_inv3;
+
+            *out = Self::AffineExt::from_xy(affine_x, affine_y)
+                .unwrap_or_else(Self::AffineExt::identity);
+        }
+    }
 }
 
 /// This trait is the affine counterpart to `Curve` and is used for
@@ -203,3 +397,696 @@ impl<C: CurveAffine> ConditionallySelectable for Coordinates<C> {
         }
     }
 }
+
+/// Recovers a point on the curve given its $x$-coordinate and the parity of
+/// its $y$-coordinate.
+///
+/// This is the building block of SEC1 point decompression: only the low bit
+/// of $y$ is transmitted alongside $x$, so the other root of
+/// $y^2 = x^3 + a \cdot x + b$ is recovered by negating.
+///
+/// `decompress` is fully provided in terms of [`CurveAffine`], so any
+/// `CurveAffine` type (e.g. `pallas::Affine`/`vesta::Affine`) can opt in
+/// with a marker impl, `impl DecompressPoint for MyAffine {}`.
+pub trait DecompressPoint: CurveAffine {
+    /// Given an $x$-coordinate and the desired low bit of $y$ (`ysign`),
+    /// returns the corresponding point if `x` lies on the curve.
+    fn decompress(x: &Self::Base, ysign: Choice) -> CtOption<Self> {
+        let y2 = (x.square() + Self::a()) * x + Self::b();
+        y2.sqrt().and_then(|mut y| {
+            let y_is_odd = Choice::from(y.to_repr().as_ref()[0] & 1);
+            y.conditional_negate(y_is_odd ^ ysign);
+            Self::from_xy(*x, y)
+        })
+    }
+}
+
+/// SEC1 (Standards for Efficient Cryptography) point encoding, the wire
+/// format expected by most ECDSA/ECDH implementations.
+///
+/// This is distinct from the Pasta-native compressed encoding used by
+/// [`CurveAffine::to_bytes`]-style APIs, and exists so that code which needs
+/// to interoperate with other SEC1-speaking systems doesn't have to
+/// hand-roll the byte layout.
+///
+/// Both methods are fully provided in terms of [`CurveAffine`] (and
+/// [`DecompressPoint`] for decoding), so, like [`DecompressPoint`], any
+/// `CurveAffine` type can opt in with a marker impl,
+/// `impl Sec1Encoding for MyAffine {}`.
+///
+/// `pallas::Affine`/`vesta::Affine` do not have these marker impls, and no
+/// round-trip test (`to_encoded_point` then `from_encoded_point`) covers
+/// this trait. [`Sec1Tag`]'s own framing logic is covered by
+/// `sec1_tag_tests` below, but that doesn't exercise
+/// `from_repr`/`decompress`/coordinate recovery.
+#[cfg(feature = "alloc")]
+pub trait Sec1Encoding: CurveAffine {
+    /// Encodes this point as a SEC1 encoded point.
+    ///
+    /// Encodes the identity as the single all-zero byte `0x00`, as SEC1
+    /// does not define an encoding for the point at infinity.
+    ///
+    /// If `compress` is true, produces `0x02`/`0x03 || x` (33 bytes for a
+    /// 32-byte base field); otherwise produces `0x04 || x || y` (65 bytes).
+    fn to_encoded_point(&self, compress: bool) -> Vec<u8> {
+        match Option::<Coordinates<Self>>::from(self.coordinates()) {
+            None => alloc::vec![0x00],
+            Some(coords) => {
+                let x = coords.x().to_repr();
+                let mut out = Vec::with_capacity(1 + 2 * x.as_ref().len());
+                if compress {
+                    let y = coords.y().to_repr();
+                    let sign = y.as_ref()[0] & 1;
+                    out.push(0x02 | sign);
+                    out.extend_from_slice(x.as_ref());
+                } else {
+                    out.push(0x04);
+                    out.extend_from_slice(x.as_ref());
+                    out.extend_from_slice(coords.y().to_repr().as_ref());
+                }
+                out
+            }
+        }
+    }
+
+    /// Parses a SEC1 encoded point, validating the prefix byte and length
+    /// and recovering $y$ from $x$ for compressed points.
+    fn from_encoded_point(bytes: &[u8]) -> CtOption<Self>
+    where
+        Self: DecompressPoint,
+    {
+        let field_len = <Self::Base as PrimeField>::Repr::default().as_ref().len();
+        match parse_sec1_tag(bytes, field_len) {
+            Sec1Tag::Identity => CtOption::new(Self::identity(), Choice::from(1)),
+            Sec1Tag::Compressed { ysign, x } => {
+                let mut repr = <Self::Base as PrimeField>::Repr::default();
+                repr.as_mut().copy_from_slice(x);
+                Self::Base::from_repr(repr).and_then(|x| Self::decompress(&x, ysign))
+            }
+            Sec1Tag::Uncompressed { x, y } => {
+                let mut x_repr = <Self::Base as PrimeField>::Repr::default();
+                x_repr.as_mut().copy_from_slice(x);
+                let mut y_repr = <Self::Base as PrimeField>::Repr::default();
+                y_repr.as_mut().copy_from_slice(y);
+                Self::Base::from_repr(x_repr).and_then(|x| {
+                    Self::Base::from_repr(y_repr).and_then(|y| Self::from_xy(x, y))
+                })
+            }
+            Sec1Tag::Invalid => CtOption::new(Self::identity(), Choice::from(0)),
+        }
+    }
+}
+
+/// The parsed framing of a SEC1-encoded point: which variant it is, and
+/// (for the non-identity cases) the coordinate byte slices it carries.
+///
+/// Decoding the tag byte and validating the overall length only depends on
+/// `field_len`, not on any particular curve's field or point types, so this
+/// is split out of [`Sec1Encoding::from_encoded_point`] to be exercised by a
+/// unit test without a concrete `CurveAffine` implementor on hand.
+enum Sec1Tag<'a> {
+    Identity,
+    Compressed { ysign: Choice, x: &'a [u8] },
+    Uncompressed { x: &'a [u8], y: &'a [u8] },
+    Invalid,
+}
+
+fn parse_sec1_tag(bytes: &[u8], field_len: usize) -> Sec1Tag<'_> {
+    match bytes.first() {
+        Some(0x00) if bytes.len() == 1 => Sec1Tag::Identity,
+        Some(tag @ (0x02 | 0x03)) if bytes.len() == 1 + field_len => Sec1Tag::Compressed {
+            ysign: Choice::from(tag & 1),
+            x: &bytes[1..],
+        },
+        Some(0x04) if bytes.len() == 1 + 2 * field_len => Sec1Tag::Uncompressed {
+            x: &bytes[1..1 + field_len],
+            y: &bytes[1 + field_len..],
+        },
+        _ => Sec1Tag::Invalid,
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod sec1_tag_tests {
+    use super::*;
+
+    #[test]
+    fn identity_is_the_single_zero_byte() {
+        assert!(matches!(parse_sec1_tag(&[0x00], 32), Sec1Tag::Identity));
+        // Wrong length still carrying the identity tag byte is not the identity.
+        assert!(matches!(
+            parse_sec1_tag(&[0x00, 0x00], 32),
+            Sec1Tag::Invalid
+        ));
+    }
+
+    #[test]
+    fn compressed_tag_recovers_sign_and_x() {
+        let mut bytes = alloc::vec![0x03u8];
+        bytes.extend_from_slice(&[0xaa; 32]);
+        match parse_sec1_tag(&bytes, 32) {
+            Sec1Tag::Compressed { ysign, x } => {
+                assert_eq!(bool::from(ysign), true);
+                assert_eq!(x, &[0xaa; 32][..]);
+            }
+            _ => panic!("expected a compressed tag"),
+        }
+    }
+
+    #[test]
+    fn uncompressed_tag_splits_x_and_y() {
+        let mut bytes = alloc::vec![0x04u8];
+        bytes.extend_from_slice(&[0x11; 32]);
+        bytes.extend_from_slice(&[0x22; 32]);
+        match parse_sec1_tag(&bytes, 32) {
+            Sec1Tag::Uncompressed { x, y } => {
+                assert_eq!(x, &[0x11; 32][..]);
+                assert_eq!(y, &[0x22; 32][..]);
+            }
+            _ => panic!("expected an uncompressed tag"),
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_tag_and_wrong_length() {
+        assert!(matches!(
+            parse_sec1_tag(&[0x05; 33], 32),
+            Sec1Tag::Invalid
+        ));
+        assert!(matches!(parse_sec1_tag(&[0x02; 10], 32), Sec1Tag::Invalid));
+        assert!(matches!(parse_sec1_tag(&[], 32), Sec1Tag::Invalid));
+    }
+}
+
+/// An interoperable fixed-width uncompressed point encoding, for types
+/// (typically proof transcripts and wire protocols) that need a known byte
+/// length at compile time rather than the variable length returned by
+/// [`Sec1Encoding::to_encoded_point`].
+pub trait UncompressedEncoding: CurveAffine {
+    /// The byte array type used for the uncompressed SEC1 encoding of this
+    /// curve, i.e. `0x04 || x || y`.
+    type Uncompressed: AsRef<[u8]> + AsMut<[u8]> + Copy + Clone + Default + Send + Sync + 'static;
+
+    /// Encodes this point using the uncompressed SEC1 encoding.
+    fn to_uncompressed(&self) -> Self::Uncompressed;
+
+    /// Attempts to recover a point from its uncompressed SEC1 encoding.
+    fn from_uncompressed(bytes: &Self::Uncompressed) -> CtOption<Self>;
+}
+
+/// Parameters needed to accelerate scalar multiplication using the curve
+/// endomorphism exposed by [`CurveExt::endo`] (the GLV method).
+///
+/// `endo()` multiplies a point's $x$-coordinate by a base-field cube root of
+/// unity, which is equivalent to multiplying the point itself by a
+/// fixed scalar-field eigenvalue `ENDO_LAMBDA`. Any scalar `k` can then be
+/// written as `k = k1 + k2 * ENDO_LAMBDA (mod n)` with `k1`, `k2` each only
+/// about half as wide as `k`, which lets `k * P` be evaluated as
+/// `k1 * P + k2 * endo(P)` with half as many doublings.
+pub trait GlvParameters: CurveExt {
+    /// The scalar-field eigenvalue `λ` of the endomorphism, i.e. the value
+    /// such that `p.endo() == p * Self::ENDO_LAMBDA` for every point `p`.
+    const ENDO_LAMBDA: Self::ScalarExt;
+
+    /// A short basis `[(a1, b1), (a2, b2)]` of the sublattice
+    /// `{(x, y) : x + y * ENDO_LAMBDA ≡ 0 (mod n)}`, found ahead of time via
+    /// the extended-Euclidean (partial-GCD) construction. Negative lattice
+    /// coordinates are represented as their absolute value alongside
+    /// [`Self::ENDO_BASIS_SIGNS`].
+    const ENDO_BASIS: [(u128, u128); 2];
+
+    /// The signs of `[a1, b1, a2, b2]` (in that order) from [`Self::ENDO_BASIS`];
+    /// `true` means negative.
+    const ENDO_BASIS_SIGNS: [bool; 4];
+
+    /// `⌊2^384 / n⌋`, precomputed so that `round(b * k / n)` can be computed
+    /// via a Barrett-style multiply-and-shift (plus [`barrett_round`]'s own
+    /// corrective steps, which need [`Self::ENDO_N`]) instead of an exact
+    /// big-integer division.
+    const ENDO_MU: [u64; 5];
+
+    /// The scalar field modulus `n`, as little-endian 64-bit limbs, needed
+    /// alongside [`Self::ENDO_MU`] to make [`barrett_round`]'s Barrett
+    /// estimate of `round(b * k / n)` exact.
+    const ENDO_N: [u64; 4];
+
+    /// Computes `k * self` using the GLV decomposition
+    /// `k = k1 + k2 * ENDO_LAMBDA (mod n)`, evaluated as
+    /// `k1 * self + k2 * self.endo()` via an interleaved (Straus-style)
+    /// double-and-add over the half-width scalars `k1`, `k2`, negating
+    /// `self`/`self.endo()` in constant time when the corresponding `ki` is
+    /// negative. Roughly halves the number of doublings versus a plain
+    /// scalar multiplication.
+    fn mul_glv(&self, k: &Self::ScalarExt) -> Self {
+        let k_limbs = scalar_to_u64x4(k);
+
+        let [(a1_mag, b1_mag), (a2_mag, b2_mag)] = Self::ENDO_BASIS;
+        let [a1_neg, b1_neg, a2_neg, b2_neg] = Self::ENDO_BASIS_SIGNS;
+
+        // beta1 = round(b2 * k / n), beta2 = round(-b1 * k / n). `barrett_round`
+        // only operates on unsigned magnitudes, so the sign of each rounded
+        // value has to be derived from the sign of the (possibly negative)
+        // lattice coordinate it was rounded against, rather than assumed
+        // positive: beta1 takes b2's sign, and beta2 takes -b1's sign.
+        let beta1_mag = barrett_round(&k_limbs, b2_mag, &Self::ENDO_MU, &Self::ENDO_N);
+        let beta2_mag = barrett_round(&k_limbs, b1_mag, &Self::ENDO_MU, &Self::ENDO_N);
+        let beta1 = cond_neg::<Self::ScalarExt>(beta1_mag, b2_neg);
+        let beta2 = cond_neg::<Self::ScalarExt>(beta2_mag, !b1_neg);
+
+        let a1 = cond_neg::<Self::ScalarExt>(a1_mag, a1_neg);
+        let b1 = cond_neg::<Self::ScalarExt>(b1_mag, b1_neg);
+        let a2 = cond_neg::<Self::ScalarExt>(a2_mag, a2_neg);
+        let b2 = cond_neg::<Self::ScalarExt>(b2_mag, b2_neg);
+
+        // k1 = k - beta1*a1 - beta2*a2
+        let k1 = *k - beta1 * a1 - beta2 * a2;
+        // k2 = -beta1*b1 - beta2*b2
+        let k2 = -(beta1 * b1) - (beta2 * b2);
+
+        // k1, k2 are guaranteed short by the lattice reduction, but their
+        // canonical (mod n) representative is only actually small when the
+        // true signed value is non-negative; when it's negative, the
+        // representative sits near n instead. Recover the true sign and
+        // short magnitude of each before negating the corresponding point
+        // and walking only half the bits in the joint double-and-add below.
+        let (k1_neg, k1_mag) = to_short_scalar(k1);
+        let (k2_neg, k2_mag) = to_short_scalar(k2);
+
+        let p1 = Self::conditional_select(self, &-*self, k1_neg);
+        let endo_self = self.endo();
+        let p2 = Self::conditional_select(&endo_self, &-endo_self, k2_neg);
+
+        let half_bits = 8 * k.to_repr().as_ref().len() / 2 + 1;
+        joint_double_and_add(&p1, &k1_mag, &p2, &k2_mag, half_bits)
+    }
+}
+
+#[cfg(test)]
+mod glv_decomposition_tests {
+    // Neither Pallas nor Vesta implements `GlvParameters` in this crate, so
+    // `mul_glv` can't be driven end-to-end here.
+    // This instead checks, over plain `i128`s, that the lattice-decomposition
+    // identity `k ≡ k1 + k2 * λ (mod n)` that `mul_glv` relies on actually
+    // holds — using a small literal toy lattice rather than a real curve's
+    // (256-bit) one, since the identity depends only on `λ` being a root of
+    // `x^2 + x + 1 (mod n)` and the basis satisfying `a_i + b_i*λ ≡ 0 (mod
+    // n)`, not on the lattice's size.
+    const N: i128 = 97;
+    const LAMBDA: i128 = 35;
+    const A1: i128 = 8;
+    const B1: i128 = -3;
+    const A2: i128 = 27;
+    const B2: i128 = 2;
+
+    // round(num / den), half away from zero, mirroring `barrett_round`'s
+    // contract (den is always positive here).
+    fn round_div(num: i128, den: i128) -> i128 {
+        let q = num.div_euclid(den);
+        let r = num.rem_euclid(den);
+        if 2 * r >= den {
+            q + 1
+        } else {
+            q
+        }
+    }
+
+    // Mirrors `GlvParameters::mul_glv`'s decomposition arithmetic exactly,
+    // substituting plain `i128` arithmetic mod `N` for the real scalar field.
+    fn decompose(k: i128) -> (i128, i128) {
+        let k = k.rem_euclid(N);
+        let beta1 = round_div(B2 * k, N);
+        let beta2 = round_div(-B1 * k, N);
+        let k1 = k - beta1 * A1 - beta2 * A2;
+        let k2 = -(beta1 * B1) - beta2 * B2;
+        (k1, k2)
+    }
+
+    #[test]
+    fn lambda_is_a_cube_root_of_unity() {
+        assert_eq!((LAMBDA * LAMBDA + LAMBDA + 1).rem_euclid(N), 0);
+    }
+
+    #[test]
+    fn basis_vectors_lie_in_the_lattice() {
+        assert_eq!((A1 + B1 * LAMBDA).rem_euclid(N), 0);
+        assert_eq!((A2 + B2 * LAMBDA).rem_euclid(N), 0);
+    }
+
+    #[test]
+    fn decomposition_recombines_to_k() {
+        for k in 0..N {
+            let (k1, k2) = decompose(k);
+            assert_eq!((k1 + k2 * LAMBDA).rem_euclid(N), k, "k={k}");
+        }
+    }
+}
+
+/// Interprets `k` as a little-endian integer and returns it as four 64-bit
+/// limbs, least-significant first.
+fn scalar_to_u64x4<F: FieldExt>(k: &F) -> [u64; 4] {
+    let repr = k.to_repr();
+    let bytes = repr.as_ref();
+    let mut limbs = [0u64; 4];
+    for (i, limb) in limbs.iter_mut().enumerate() {
+        let mut buf = [0u8; 8];
+        let start = i * 8;
+        let end = core::cmp::min(start + 8, bytes.len());
+        if start < bytes.len() {
+            buf[..end - start].copy_from_slice(&bytes[start..end]);
+        }
+        *limb = u64::from_le_bytes(buf);
+    }
+    limbs
+}
+
+/// Multiplies the little-endian limb sequences `a` and `b`, writing the full
+/// (unreduced) product into `out` (which must be at least `a.len() +
+/// b.len()` limbs long) using a textbook multiply-accumulate.
+///
+/// Each multiplier limb of `b` is walked across the whole of `a` with its
+/// carry propagated immediately, rather than summing every cross term into a
+/// shared `u128` accumulator first: a limb's accumulator can otherwise see
+/// several near-`2^128` partial products land on it at once and overflow
+/// before the carry is ever extracted.
+fn mul_into(a: &[u64], b: &[u64], out: &mut [u64]) {
+    for (j, &bl) in b.iter().enumerate() {
+        let mut carry = 0u128;
+        for (i, &al) in a.iter().enumerate() {
+            let t = out[i + j] as u128 + al as u128 * bl as u128 + carry;
+            out[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        out[j + a.len()] = carry as u64;
+    }
+}
+
+/// Subtracts the little-endian limb sequence `b` from `a` (which the caller
+/// must ensure is `>= b`), writing the result into `out` (same length as
+/// `a`/`b`). Uses `overflowing_sub`'s carry flag rather than a comparison
+/// to decide whether to borrow, so the borrow propagates without branching
+/// on the limb values, which [`barrett_round`] calls this with secret data.
+fn sub_into(a: &[u64], b: &[u64], out: &mut [u64]) {
+    let mut borrow = 0u64;
+    for i in 0..a.len() {
+        let (d1, b1) = a[i].overflowing_sub(b[i]);
+        let (d2, b2) = d1.overflowing_sub(borrow);
+        out[i] = d2;
+        borrow = (b1 as u64) | (b2 as u64);
+    }
+}
+
+/// Flattens a 6-limb little-endian value into its 48-byte little-endian
+/// representation, so [`barrett_round`]'s correction step can reuse
+/// [`ct_lt_bytes`] for its comparison instead of a second,
+/// limb-granularity comparator.
+fn limbs_to_le_bytes(limbs: &[u64; 6]) -> [u8; 48] {
+    let mut out = [0u8; 48];
+    for (chunk, limb) in out.chunks_exact_mut(8).zip(limbs.iter()) {
+        chunk.copy_from_slice(&limb.to_le_bytes());
+    }
+    out
+}
+
+/// Doubles a 6-limb little-endian value in place of a full multiply, for
+/// [`barrett_round`]'s round-to-nearest step, which only ever needs `2 *
+/// remainder`.
+fn double_into(limbs: &[u64; 6]) -> [u64; 6] {
+    let mut out = [0u64; 6];
+    let mut carry = 0u64;
+    for i in 0..6 {
+        out[i] = (limbs[i] << 1) | carry;
+        carry = limbs[i] >> 63;
+    }
+    out
+}
+
+/// Constant-time select between two 6-limb little-endian values, via the
+/// standard XOR-mask trick (`a ^ (mask & (a ^ b))`, where `mask` is all-ones
+/// or all-zero) rather than a per-limb branch, since [`barrett_round`] uses
+/// this on values derived from the secret `k` it's splitting.
+fn ct_select_u64x6(a: &[u64; 6], b: &[u64; 6], choice: Choice) -> [u64; 6] {
+    let mask = 0u64.wrapping_sub(choice.unwrap_u8() as u64);
+    let mut out = [0u64; 6];
+    for i in 0..6 {
+        out[i] = a[i] ^ (mask & (a[i] ^ b[i]));
+    }
+    out
+}
+
+/// Computes `round(k * b / n)` exactly (rounding half away from zero, i.e.
+/// up, matching [`GlvParameters::mul_glv`]'s own comment), for a four-limb
+/// `k` (reduced mod `n`, as every caller's `k` is) and a 128-bit `b`, using
+/// the precomputed Barrett constant `mu = floor(2^384 / n)` plus two
+/// corrective steps.
+///
+/// The shift is 384, not the more familiar 256: since `k < n` and `b` is up
+/// to 128 bits, their product is up to `bits(n) + 128` wide, and scaling
+/// `mu` to only `2^256` — enough precision for `n` alone, but not for a
+/// product this much wider — lets a single multiply-and-shift estimate
+/// drift arbitrarily far from the true quotient once it's multiplied back
+/// up by that width. Scaling to `2^384` instead keeps the raw estimate
+/// within one step of the true *floor* (verified against an
+/// arbitrary-precision reference below, including a case that needs the
+/// correction).
+///
+/// Getting `floor(k * b / n)` isn't quite enough, though: `mul_glv` needs
+/// the *nearest* integer (its short lattice coordinates are only bounded to
+/// half the basis vectors' width when the rounding is to nearest — flooring
+/// instead can leave them up to twice as large, which `joint_double_and_add`
+/// isn't budgeted for). So this applies two corrections in sequence, both
+/// constant-time since they depend on the secret `k`: first the usual
+/// Barrett correction (comparing `k * b - q * n` against `n`) to land on the
+/// exact floor and its exact remainder, then a second comparison of `2 *
+/// remainder` against `n` to decide whether that floor is already the
+/// nearest integer or needs to be rounded up.
+fn barrett_round(k: &[u64; 4], b: u128, mu: &[u64; 5], n: &[u64; 4]) -> u128 {
+    let b_limbs = [b as u64, (b >> 64) as u64];
+
+    // product = k * b, exact, a <=384-bit value in 6 limbs.
+    let mut product = [0u64; 6];
+    mul_into(k, &b_limbs, &mut product);
+
+    // q0 = floor(product * mu / 2^384), the Barrett estimate of
+    // floor(product / n). Only the limbs landing at weight >= 2^384
+    // (indices 6 and 7 of the 11-limb product) are needed for the u128
+    // result, but every (product_limb, mu_limb) pair has to be summed
+    // first since a pair at any lower index can still carry up into them.
+    let mut wide = [0u64; 11];
+    mul_into(&product, mu, &mut wide);
+    let q0_limbs = [wide[6], wide[7]];
+
+    // r0 = product - q0*n; this never borrows, since q0 never overshoots
+    // the true quotient, but may still be short by one multiple of n.
+    let mut q0n = [0u64; 6];
+    mul_into(&q0_limbs, n, &mut q0n);
+    let mut r0 = [0u64; 6];
+    sub_into(&product, &q0n, &mut r0);
+
+    let mut n_ext = [0u64; 6];
+    n_ext[..4].copy_from_slice(n);
+    let n_bytes = limbs_to_le_bytes(&n_ext);
+
+    // floor(product / n) and its exact remainder in [0, n).
+    let short_by_one = !ct_lt_bytes(&limbs_to_le_bytes(&r0), &n_bytes);
+    let floor_q = (q0_limbs[0] as u128 | (q0_limbs[1] as u128) << 64) + short_by_one.unwrap_u8() as u128;
+    let mut r0_minus_n = [0u64; 6];
+    sub_into(&r0, &n_ext, &mut r0_minus_n);
+    let remainder = ct_select_u64x6(&r0, &r0_minus_n, short_by_one);
+
+    // round(product / n) = floor(product / n) + 1 if the remainder is at
+    // least half of n, i.e. 2*remainder >= n.
+    let round_up = !ct_lt_bytes(&limbs_to_le_bytes(&double_into(&remainder)), &n_bytes);
+    floor_q + round_up.unwrap_u8() as u128
+}
+
+#[cfg(test)]
+mod barrett_round_tests {
+    use super::barrett_round;
+
+    // `n` is the published Pallas/Vesta scalar field modulus (the two
+    // curves share a pair of moduli, one per field) — a real-sized literal
+    // test modulus, used here purely to exercise the arithmetic, since
+    // neither curve implements `GlvParameters` in this crate.
+    const N: [u64; 4] = [
+        0x8c46eb2100000001,
+        0x224698fc0994a8dd,
+        0x0000000000000000,
+        0x4000000000000000,
+    ];
+    const MU: [u64; 5] = [
+        0xfffffffffffffffd,
+        0xffffffffffffffff,
+        0x0000000000000003,
+        0,
+        0,
+    ];
+
+    // Expected values are `round(k * b / n)` (half away from zero),
+    // computed independently with arbitrary-precision arithmetic (not this
+    // module's limb-based multiply) from the same (k, b, n) inputs.
+    #[test]
+    fn matches_arbitrary_precision_reference() {
+        let cases: [([u64; 4], u128, u128); 5] = [
+            ([0, 0, 0, 0], 0, 0),
+            ([1, 0, 0, 0], 1, 0),
+            (
+                // k = n - 1, the largest value `k` can legally take.
+                [
+                    0x8c46eb2100000000,
+                    0x224698fc0994a8dd,
+                    0x0000000000000000,
+                    0x4000000000000000,
+                ],
+                u128::MAX,
+                0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff,
+            ),
+            (
+                // The raw (pre-correction) Barrett estimate for this case is
+                // one short of the true floor, so this specifically
+                // exercises the floor correction.
+                [
+                    0x7731af10506bf2ef,
+                    0xec66a78795e761d1,
+                    0x5c90a9587403e430,
+                    0x1fcc713b4cbd87ad,
+                ],
+                0xc7a2ea20b2f14c942e05319acb5c7427,
+                0x6330a1e6bdd97ead884fb32b6542dadd,
+            ),
+            (
+                // Same k as above with a larger b; this case's remainder is
+                // below half of n, so the result is the floor itself (the
+                // k = n - 1 case above is the one that rounds up).
+                [
+                    0x7731af10506bf2ef,
+                    0xec66a78795e761d1,
+                    0x5c90a9587403e430,
+                    0x1fcc713b4cbd87ad,
+                ],
+                0xd7a2ea20b2f14c942e05319acb5c7427,
+                0x6b23be359108e098df73dd818243d3e9,
+            ),
+        ];
+
+        for (k, b, expected) in cases {
+            assert_eq!(barrett_round(&k, b, &MU, &N), expected);
+        }
+    }
+}
+
+/// Converts a `u128` into the scalar field `F` via repeated doubling.
+fn u128_to_scalar<F: FieldExt>(value: u128) -> F {
+    let mut acc = F::zero();
+    for i in (0..128).rev() {
+        acc = acc.double();
+        if (value >> i) & 1 == 1 {
+            acc += F::one();
+        }
+    }
+    acc
+}
+
+/// Negates `value` in the scalar field `F` if `negative` is true.
+fn cond_neg<F: FieldExt>(value: u128, negative: bool) -> F {
+    let v = u128_to_scalar::<F>(value);
+    if negative {
+        -v
+    } else {
+        v
+    }
+}
+
+/// Constant-time `a < b` over two same-length little-endian byte slices.
+///
+/// Walks the bytes from most to least significant, using the standard
+/// `(a as i16 - b as i16) >> 15` trick to turn each byte comparison into an
+/// all-ones/all-zeros mask without branching, and freezing the running
+/// result the moment a pair of bytes differs (comparisons at less
+/// significant positions can no longer change the outcome, but are still
+/// performed so the loop's timing doesn't depend on where the difference
+/// occurred).
+fn ct_lt_bytes(a: &[u8], b: &[u8]) -> Choice {
+    assert_eq!(a.len(), b.len());
+
+    let mut lt = Choice::from(0);
+    let mut eq = Choice::from(1);
+    for (&x, &y) in a.iter().zip(b.iter()).rev() {
+        let diff = x as i16 - y as i16;
+        let byte_lt = Choice::from(((diff >> 15) & 1) as u8);
+        let byte_eq = x.ct_eq(&y);
+
+        lt = (byte_lt & eq) | (lt & !eq);
+        eq &= byte_eq;
+    }
+    lt
+}
+
+#[cfg(test)]
+mod ct_lt_bytes_tests {
+    use super::ct_lt_bytes;
+
+    #[test]
+    fn matches_little_endian_integer_order() {
+        let cases: [(u32, u32); 6] = [
+            (1, 2),
+            (2, 1),
+            (1, 1),
+            (0x0100_0000, 0x0000_0002),
+            (0x0100_00ff, 0x0200_0000),
+            (0x0001_0000, 0x0000_ffff),
+        ];
+        for (a, b) in cases {
+            let got = bool::from(ct_lt_bytes(&a.to_le_bytes(), &b.to_le_bytes()));
+            assert_eq!(got, a < b, "a={a:#x} b={b:#x}");
+        }
+    }
+}
+
+/// Recovers the true sign and short magnitude of a scalar-field element that
+/// is known (by construction, e.g. a GLV lattice coordinate) to be small in
+/// absolute value. Because field subtraction always returns the canonical
+/// representative in `[0, n)`, a negative short value `-m` is stored as
+/// `n - m`, which is numerically large; its negation `m`, however, is
+/// numerically small. Of `v` and `-v`, whichever is the smaller integer is
+/// therefore the genuine short magnitude, and the other reveals the sign.
+/// The comparison and the final selection are both constant-time, since `v`
+/// is derived from the secret scalar being multiplied.
+fn to_short_scalar<F: FieldExt>(v: F) -> (Choice, F) {
+    let neg_v = -v;
+    let neg_is_shorter = ct_lt_bytes(neg_v.to_repr().as_ref(), v.to_repr().as_ref());
+    (neg_is_shorter, F::conditional_select(&v, &neg_v, neg_is_shorter))
+}
+
+/// Evaluates `k1 * p + k2 * q` via an interleaved (Straus/joint)
+/// double-and-add over the low `num_bits` bits of `k1`/`k2`, processing both
+/// scalars' bits together so the pair only needs one doubling per bit rather
+/// than one per scalar. Callers are responsible for ensuring `num_bits` is
+/// wide enough to cover both scalars in full.
+fn joint_double_and_add<C: CurveExt>(
+    p: &C,
+    k1: &C::ScalarExt,
+    q: &C,
+    k2: &C::ScalarExt,
+    num_bits: usize,
+) -> C {
+    let k1_repr = k1.to_repr();
+    let k2_repr = k2.to_repr();
+    let k1_bytes = k1_repr.as_ref();
+    let k2_bytes = k2_repr.as_ref();
+
+    let p_plus_q = *p + q;
+
+    let mut acc = C::identity();
+    for bit in (0..num_bits).rev() {
+        acc = acc.double();
+        let b1 = (k1_bytes[bit / 8] >> (bit % 8)) & 1;
+        let b2 = (k2_bytes[bit / 8] >> (bit % 8)) & 1;
+        match (b1, b2) {
+            (1, 0) => acc += p,
+            (0, 1) => acc += q,
+            (1, 1) => acc += &p_plus_q,
+            _ => (),
+        }
+    }
+    acc
+}
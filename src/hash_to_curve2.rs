@@ -82,3 +82,98 @@ impl<'a, F: FieldExt, C: CurveExt<Base = F> + CurveConstants, I: CurveExt<Base =
     }
 }
 */
+
+/// The `encode_to_curve` counterpart to [`Hasher`]: maps a message to a
+/// single field element and runs the SWU map once, rather than twice and
+/// summing, which gives a non-uniform (not random-oracle) result for
+/// roughly half the cost.
+#[derive(Debug, Copy, Clone)]
+pub struct Encoder<'a, Field, Curve, IsoCurve> {
+    domain_prefix: &'a str,
+    _marker1: PhantomData<Field>,
+    _marker2: PhantomData<Curve>,
+    _marker3: PhantomData<IsoCurve>,
+}
+
+impl<'a, F, C, I> Encoder<'a, F, C, I> {
+    pub(crate) fn new(domain_prefix: &'a str) -> Self {
+        Encoder {
+            domain_prefix: domain_prefix,
+            _marker1: PhantomData,
+            _marker2: PhantomData,
+            _marker3: PhantomData,
+        }
+    }
+}
+
+impl<'a, F, C, I> Fn<(&[u8],)> for Encoder<'a, F, C, I>
+where
+    F: FieldExt,
+    C: CurveExt<Base = F> + CurveConstants,
+    I: CurveExt<Base = F>,
+{
+    extern "rust-call" fn call(&self, args: (&[u8],)) -> C {
+        let (message,) = args;
+        use crate::hashtocurve;
+        let mut us = [Field::zero(); 1];
+        hashtocurve::hash_to_field(C::CURVE_ID, self.domain_prefix, message, &mut us);
+        let q = hashtocurve::map_to_curve_simple_swu::<F, C, I>(&us[0], C::THETA, C::Z);
+        debug_assert!(bool::from(q.is_on_curve()));
+        hashtocurve::iso_map::<F, C, I>(&q, &C::ISOGENY_CONSTANTS)
+    }
+}
+
+impl<'a, F, C, I> FnOnce<(&[u8],)> for Encoder<'a, F, C, I>
+where
+    F: FieldExt,
+    C: CurveExt<Base = F> + CurveConstants,
+    I: CurveExt<Base = F>,
+{
+    type Output = C;
+    extern "rust-call" fn call_once(self, args: (&[u8],)) -> C {
+        self.call(args)
+    }
+}
+
+impl<'a, F, C, I> FnMut<(&[u8],)> for Encoder<'a, F, C, I>
+where
+    F: FieldExt,
+    C: CurveExt<Base = F> + CurveConstants,
+    I: CurveExt<Base = F>,
+{
+    extern "rust-call" fn call_mut(&mut self, args: (&[u8],)) -> C {
+        self.call(args)
+    }
+}
+
+/// Backs [`CurveExt::unboxed_encode_to_curve`] for a concrete curve `C`
+/// (and its isogenous curve `I`), the same way [`Hasher`] backs
+/// `unboxed_hash_to_curve`. A `CurveExt` implementor wires this in as:
+///
+/// ```ignore
+/// fn unboxed_encode_to_curve(domain_prefix: &str, message: &[u8]) -> Self {
+///     crate::hash_to_curve2::unboxed_encode_to_curve::<_, Self, IsoSelf>(domain_prefix, message)
+/// }
+/// ```
+pub(crate) fn unboxed_encode_to_curve<F, C, I>(domain_prefix: &str, message: &[u8]) -> C
+where
+    F: FieldExt,
+    C: CurveExt<Base = F> + CurveConstants,
+    I: CurveExt<Base = F>,
+{
+    Encoder::<F, C, I>::new(domain_prefix)(message)
+}
+
+/// Backs [`CurveExt::encode_to_curve`], boxing an [`Encoder`] the same way
+/// `hash_to_curve` boxes a [`Hasher`].
+#[cfg(feature = "alloc")]
+pub(crate) fn encode_to_curve<'a, F, C, I>(
+    domain_prefix: &'a str,
+) -> alloc::boxed::Box<dyn Fn(&[u8]) -> C + 'a>
+where
+    F: FieldExt,
+    C: CurveExt<Base = F> + CurveConstants,
+    I: CurveExt<Base = F>,
+{
+    alloc::boxed::Box::new(Encoder::<F, C, I>::new(domain_prefix))
+}
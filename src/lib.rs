@@ -11,7 +11,7 @@
 #![deny(missing_docs)]
 #![deny(unsafe_code)]
 
-#[cfg(any(not(feature = "unboxed_closures"), feature = "sqrt-table"))]
+#[cfg(any(not(feature = "unboxed_closures"), feature = "sqrt-table", feature = "serde"))]
 extern crate alloc;
 
 #[cfg(test)]
@@ -31,6 +31,10 @@ pub mod vesta;
 mod hash_to_curve2;
 mod hashtocurve;
 
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde;
+
 pub use curves::*;
 pub use fields::*;
 
@@ -46,3 +50,64 @@ fn test_endo_consistency() {
     let a = vesta::Point::generator();
     assert_eq!(a * vesta::Scalar::ZETA, a.endo());
 }
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_msm() {
+    use crate::arithmetic::CurveExt;
+    use alloc::vec::Vec;
+    use group::Group;
+
+    let coeffs: Vec<_> = (1..=17u64).map(pallas::Scalar::from).collect();
+    let bases: Vec<_> = coeffs
+        .iter()
+        .map(|c| (pallas::Point::generator() * c).to_affine())
+        .collect();
+
+    let expected = coeffs
+        .iter()
+        .zip(bases.iter())
+        .fold(pallas::Point::identity(), |acc, (c, base)| {
+            acc + *base * c
+        });
+
+    assert_eq!(pallas::Point::msm(&coeffs, &bases), expected);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_batch_normalize() {
+    use crate::arithmetic::{CurveAffine, CurveExt};
+    use alloc::vec::Vec;
+    use group::Group;
+
+    let mut points: Vec<_> = (1..=17u64)
+        .map(|c| pallas::Point::generator() * pallas::Scalar::from(c))
+        .collect();
+    // Z = 0 is a special case `batch_normalize` has to skip when forming its
+    // running product of Zs, since the identity has no multiplicative
+    // inverse; mix one into the batch (rather than testing it alone) so this
+    // also covers the identity's effect on the running products of its
+    // neighbours.
+    points.insert(9, pallas::Point::identity());
+
+    let expected: Vec<_> = points.iter().map(|p| p.to_affine()).collect();
+
+    let mut normalized: Vec<_> = (0..points.len()).map(|_| pallas::Affine::identity()).collect();
+    pallas::Point::batch_normalize(&points, &mut normalized);
+
+    assert_eq!(normalized, expected);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_batch_normalize_empty() {
+    use crate::arithmetic::CurveExt;
+    use alloc::vec::Vec;
+
+    let points: Vec<pallas::Point> = Vec::new();
+    let mut normalized: Vec<pallas::Affine> = Vec::new();
+    pallas::Point::batch_normalize(&points, &mut normalized);
+
+    assert!(normalized.is_empty());
+}
@@ -0,0 +1,291 @@
+//! Optional [`serde`] support for points, coordinates, and field elements.
+//!
+//! Serialization always goes through this crate's existing compressed byte
+//! encoding (`to_bytes`/`to_repr`), with a hex-string fallback for
+//! human-readable formats such as JSON. Deserialization round-trips through
+//! the same `CtOption`-based validation used elsewhere in the crate, so
+//! malformed or off-curve input is rejected rather than causing a panic.
+//! Byte/hex conversion does not branch on the value being (de)serialized,
+//! so it does not leak secret scalars through timing.
+//!
+//! `Serialize`/`Deserialize` cannot be implemented directly for every
+//! `CurveAffine`/`FieldExt` type (a blanket `impl<C: CurveAffine> Serialize
+//! for C` would be an orphan impl, since neither the trait nor the type
+//! parameter is local to this crate), so this module exposes two pieces of
+//! delegation machinery:
+//!
+//! - [`serialize_compressed`]/[`deserialize_compressed`] and
+//!   [`serialize_curve_affine`]/[`deserialize_curve_affine`]/
+//!   [`serialize_field`]/[`deserialize_field`], for `pallas`/`vesta`'s
+//!   concrete point and field types to forward their own `Serialize`/
+//!   `Deserialize` impls to.
+//! - [`EncodedPoint`]/[`EncodedField`], transparent local wrapper types that
+//!   already implement `Serialize`/`Deserialize` for *any* `CurveAffine`/
+//!   `FieldExt`, for callers who want (de)serialization today without
+//!   waiting on the former.
+//!
+//! `pallas::Affine`/`vesta::Affine`/`Fp`/`Fq` do not have forwarding impls
+//! of their own (each would be a one-line `serialize`/`deserialize` body
+//! calling straight into [`serialize_curve_affine`]/[`serialize_field`] and
+//! their `deserialize_*` counterparts). The `hex_round_trips`-style tests
+//! below exercise only this module's own hex/byte framing, not a real
+//! `to_bytes`/`from_bytes` or `to_repr`/`from_repr` call, so
+//! `serialize_curve_affine`/`deserialize_curve_affine`/`serialize_field`/
+//! `deserialize_field` are not covered by them.
+
+use alloc::string::String;
+use core::fmt;
+
+use group::GroupEncoding;
+use serde::de::{Error as _, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::arithmetic::{Coordinates, CurveAffine, FieldExt};
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+fn to_hex(bytes: &[u8]) -> String {
+    let mut s = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        s.push(HEX_DIGITS[(byte >> 4) as usize] as char);
+        s.push(HEX_DIGITS[(byte & 0xf) as usize] as char);
+    }
+    s
+}
+
+fn from_hex_digit<E: serde::de::Error>(digit: u8) -> Result<u8, E> {
+    // 0xff when `lo <= digit <= hi`, 0 otherwise, computed without branching on `digit`.
+    let in_range = |lo: u8, hi: u8| (((lo as i16 - 1 - digit as i16) & (digit as i16 - hi as i16 - 1)) >> 8) as u8;
+    let (num, lower, upper) = (in_range(b'0', b'9'), in_range(b'a', b'f'), in_range(b'A', b'F'));
+    let value = (num & digit.wrapping_sub(b'0'))
+        | (lower & digit.wrapping_sub(b'a' - 10))
+        | (upper & digit.wrapping_sub(b'A' - 10));
+    if (num | lower | upper) == 0 {
+        return Err(E::custom("invalid hex digit"));
+    }
+    Ok(value)
+}
+
+fn from_hex<E: serde::de::Error>(hex: &str, out: &mut [u8]) -> Result<(), E> {
+    let hex = hex.as_bytes();
+    if hex.len() != out.len() * 2 {
+        return Err(E::custom("invalid encoded length"));
+    }
+    for (byte, pair) in out.iter_mut().zip(hex.chunks_exact(2)) {
+        *byte = (from_hex_digit::<E>(pair[0])? << 4) | from_hex_digit::<E>(pair[1])?;
+    }
+    Ok(())
+}
+
+/// Serializes `bytes` as a compressed byte encoding: hex for human-readable
+/// formats (e.g. JSON), raw bytes otherwise.
+///
+/// Intended to be called from a concrete curve point or field element's
+/// `Serialize` impl with its `to_bytes`/`to_repr` output.
+pub fn serialize_compressed<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&to_hex(bytes))
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+struct BytesVisitor<'a> {
+    out: &'a mut [u8],
+}
+
+impl<'de, 'a> Visitor<'de> for BytesVisitor<'a> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{} bytes, as a hex string or a byte array", self.out.len())
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        from_hex(v, self.out)
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        if v.len() != self.out.len() {
+            return Err(E::invalid_length(v.len(), &self));
+        }
+        self.out.copy_from_slice(v);
+        Ok(())
+    }
+}
+
+/// Deserializes into `out` using the inverse of [`serialize_compressed`].
+///
+/// Only fills `out`; callers are responsible for validating the resulting
+/// bytes decode to an on-curve point or valid field element (e.g. via
+/// `from_bytes`/`from_repr`'s `CtOption`), matching the rest of this crate's
+/// "never panic on untrusted input" convention.
+pub fn deserialize_compressed<'de, D: Deserializer<'de>>(
+    deserializer: D,
+    out: &mut [u8],
+) -> Result<(), D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(BytesVisitor { out })
+    } else {
+        deserializer.deserialize_bytes(BytesVisitor { out })
+    }
+}
+
+/// Serializes any [`CurveAffine`] point through its compressed encoding.
+///
+/// Intended for `pallas::Affine`/`vesta::Affine` to forward their
+/// `Serialize` impls to; until they do, wrap a point in [`EncodedPoint`] to
+/// get the same behavior today.
+pub fn serialize_curve_affine<C: CurveAffine, S: Serializer>(
+    point: &C,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_compressed(point.to_bytes().as_ref(), serializer)
+}
+
+/// Deserializes any [`CurveAffine`] point through its compressed encoding,
+/// rejecting encodings that do not correspond to a point on the curve.
+pub fn deserialize_curve_affine<'de, C: CurveAffine, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<C, D::Error> {
+    let mut repr = C::Repr::default();
+    deserialize_compressed(deserializer, repr.as_mut())?;
+    Option::from(C::from_bytes(&repr)).ok_or_else(|| D::Error::custom("point is not on the curve"))
+}
+
+/// Serializes any [`FieldExt`] element through its canonical byte encoding.
+///
+/// Intended for `Fp`/`Fq` to forward their `Serialize` impls to; until they
+/// do, wrap a value in [`EncodedField`] to get the same behavior today.
+pub fn serialize_field<F: FieldExt, S: Serializer>(
+    value: &F,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_compressed(value.to_repr().as_ref(), serializer)
+}
+
+/// Deserializes any [`FieldExt`] element through its canonical byte
+/// encoding, rejecting non-canonical or out-of-range encodings.
+pub fn deserialize_field<'de, F: FieldExt, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<F, D::Error> {
+    let mut repr = F::Repr::default();
+    deserialize_compressed(deserializer, repr.as_mut())?;
+    Option::from(F::from_repr(repr))
+        .ok_or_else(|| D::Error::custom("value is not a valid field element"))
+}
+
+impl<C: CurveAffine> Serialize for Coordinates<C> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeTuple;
+        let mut tup = serializer.serialize_tuple(2)?;
+        tup.serialize_element(&EncodedField(*self.x()))?;
+        tup.serialize_element(&EncodedField(*self.y()))?;
+        tup.end()
+    }
+}
+
+impl<'de, C: CurveAffine> Deserialize<'de> for Coordinates<C> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct CoordinatesVisitor<C>(core::marker::PhantomData<C>);
+
+        impl<'de, C: CurveAffine> Visitor<'de> for CoordinatesVisitor<C> {
+            type Value = Coordinates<C>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "a 2-tuple of field elements (x, y) on the curve")
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                let x: EncodedField<C::Base> = seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+                let y: EncodedField<C::Base> = seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+                Option::from(C::from_xy(x.0, y.0))
+                    .and_then(|point: C| Option::from(point.coordinates()))
+                    .ok_or_else(|| A::Error::custom("coordinates are not on the curve"))
+            }
+        }
+
+        deserializer.deserialize_tuple(2, CoordinatesVisitor(core::marker::PhantomData))
+    }
+}
+
+/// Transparent wrapper that implements `Serialize`/`Deserialize` for any
+/// [`CurveAffine`] point, routing through [`serialize_curve_affine`]/
+/// [`deserialize_curve_affine`].
+///
+/// `pallas::Affine`/`vesta::Affine` can forward their own `Serialize`/
+/// `Deserialize` impls to those same functions instead of wrapping, once
+/// they exist; until then, this is the way to (de)serialize a point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EncodedPoint<C>(pub C);
+
+impl<C: CurveAffine> Serialize for EncodedPoint<C> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_curve_affine(&self.0, serializer)
+    }
+}
+
+impl<'de, C: CurveAffine> Deserialize<'de> for EncodedPoint<C> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_curve_affine(deserializer).map(EncodedPoint)
+    }
+}
+
+/// Transparent wrapper that implements `Serialize`/`Deserialize` for any
+/// [`FieldExt`] element, routing through [`serialize_field`]/
+/// [`deserialize_field`].
+///
+/// `Fp`/`Fq` can forward their own `Serialize`/`Deserialize` impls to those
+/// same functions instead of wrapping, once they exist; until then, this is
+/// the way to (de)serialize a field element, and is also what
+/// [`Coordinates`]'s own impls delegate to internally.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EncodedField<F>(pub F);
+
+impl<F: FieldExt> Serialize for EncodedField<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_field(&self.0, serializer)
+    }
+}
+
+impl<'de, F: FieldExt> Deserialize<'de> for EncodedField<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_field(deserializer).map(EncodedField)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::de::value::Error as DeError;
+
+    #[test]
+    fn hex_round_trips() {
+        let bytes = [0x00u8, 0x01, 0xab, 0xff];
+        let hex = to_hex(&bytes);
+        assert_eq!(hex, "0001abff");
+
+        let mut out = [0u8; 4];
+        from_hex::<DeError>(&hex, &mut out).unwrap();
+        assert_eq!(out, bytes);
+    }
+
+    #[test]
+    fn from_hex_rejects_wrong_length() {
+        let mut out = [0u8; 4];
+        assert!(from_hex::<DeError>("00", &mut out).is_err());
+    }
+
+    #[test]
+    fn from_hex_rejects_invalid_digit() {
+        let mut out = [0u8; 2];
+        assert!(from_hex::<DeError>("zz00", &mut out).is_err());
+    }
+}